@@ -1,6 +1,7 @@
+use crate::gas::{GasOracle, NonceManager};
 use alloy::{
     network::{AnyNetwork, EthereumWallet},
-    primitives::Address,
+    primitives::{Address, FixedBytes, Signature},
     providers::{
         fillers::{
             BlobGasFiller, ChainIdFiller, FillProvider, GasFiller, JoinFill, NonceFiller,
@@ -8,12 +9,13 @@ use alloy::{
         },
         Identity, Provider, ProviderBuilder, RootProvider,
     },
-    signers::local::PrivateKeySigner,
+    signers::{local::PrivateKeySigner, Signer},
     transports::http::{Client, Http},
 };
+use async_trait::async_trait;
 use std::error::Error;
 
-type ProviderType = FillProvider<
+pub(crate) type ProviderType = FillProvider<
     JoinFill<
         JoinFill<
             Identity,
@@ -26,12 +28,66 @@ type ProviderType = FillProvider<
     AnyNetwork,
 >;
 
+/// Produces signatures for the account `Config` is acting as. `LocalSigner`
+/// below is the only implementation today, but this is the seam a Ledger or
+/// AWS-KMS integration would plug into later — `primitives::sign` only ever
+/// talks to this trait, never to key material directly.
+#[async_trait]
+pub trait SignerBackend: Send + Sync {
+    async fn sign_hash(&self, hash: FixedBytes<32>) -> Result<Signature, Box<dyn Error>>;
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, Box<dyn Error>>;
+
+    fn address(&self) -> Address;
+
+    fn chain_id(&self) -> u64;
+}
+
+/// The default backend, wrapping a raw private key held in memory.
+pub struct LocalSigner {
+    signer: PrivateKeySigner,
+}
+
+impl LocalSigner {
+    pub fn new(private_key: &str, chain_id: u64) -> Result<Self, Box<dyn Error>> {
+        let signer: PrivateKeySigner = private_key.parse().map_err(|_| "Invalid private key")?;
+        let signer = signer.with_chain_id(Some(chain_id));
+
+        Ok(LocalSigner { signer })
+    }
+}
+
+#[async_trait]
+impl SignerBackend for LocalSigner {
+    async fn sign_hash(&self, hash: FixedBytes<32>) -> Result<Signature, Box<dyn Error>> {
+        Ok(self.signer.sign_hash(&hash).await?)
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, Box<dyn Error>> {
+        Ok(self.signer.sign_message(message).await?)
+    }
+
+    fn address(&self) -> Address {
+        self.signer.address()
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.signer.chain_id().unwrap_or_default()
+    }
+}
+
 pub struct Config {
-    pub private_key: String,
+    pub signer: Box<dyn SignerBackend>,
     pub caller: Address,
     pub etherscan_key: String,
     pub provider: ProviderType,
     pub chain_id: u64,
+    /// Overrides `alloy`'s recommended-fillers fee strategy when set; falls
+    /// back to the provider's own `GasFiller` otherwise.
+    pub gas_oracle: Option<Box<dyn GasOracle>>,
+    /// Overrides the provider's `NonceFiller` when set, so many transactions
+    /// can be dispatched back-to-back without colliding on the same nonce.
+    pub nonce_manager: Option<NonceManager>,
 }
 
 impl Config {
@@ -40,8 +96,9 @@ impl Config {
         rpc_url: String,
         etherscan_key: String,
     ) -> Result<Self, Box<dyn Error>> {
-        let signer: PrivateKeySigner = private_key.parse().map_err(|_| "Invalid private key")?;
-        let wallet = EthereumWallet::from(signer);
+        let wallet_signer: PrivateKeySigner =
+            private_key.parse().map_err(|_| "Invalid private key")?;
+        let wallet = EthereumWallet::from(wallet_signer);
         let address = wallet.default_signer().address();
 
         let provider = ProviderBuilder::new()
@@ -51,17 +108,32 @@ impl Config {
             .on_http(rpc_url.clone().parse()?);
 
         let chain_id = provider.get_chain_id().await?;
+        let signer: Box<dyn SignerBackend> = Box::new(LocalSigner::new(&private_key, chain_id)?);
 
         Ok(Config {
-            private_key,
+            signer,
             caller: address,
             etherscan_key,
             provider,
             chain_id,
+            gas_oracle: None,
+            nonce_manager: None,
         })
     }
 
     pub fn peek(self) -> Address {
         self.caller
     }
+
+    /// Layers a custom [`GasOracle`] over the provider's default `GasFiller`.
+    pub fn with_gas_oracle(mut self, gas_oracle: Box<dyn GasOracle>) -> Self {
+        self.gas_oracle = Some(gas_oracle);
+        self
+    }
+
+    /// Layers a custom [`NonceManager`] over the provider's default `NonceFiller`.
+    pub fn with_nonce_manager(mut self, nonce_manager: NonceManager) -> Self {
+        self.nonce_manager = Some(nonce_manager);
+        self
+    }
 }
@@ -0,0 +1,159 @@
+#![allow(missing_docs)]
+
+use crate::config::Config;
+use alloy::{
+    primitives::{keccak256, Address, Bytes, FixedBytes, Signature, U256},
+    rpc::types::AnyTransactionReceipt,
+    sol,
+    sol_types::{eip712_domain, SolValue},
+};
+use std::error::Error;
+
+/// The base GSN-style forward-request type (matching OpenZeppelin's
+/// `MinimalForwarder`), before any trusted-forwarder-specific suffix fields
+/// are appended to the EIP-712 type string.
+const FORWARD_REQUEST_TYPE: &str =
+    "ForwardRequest(address from,address to,uint256 value,uint256 gas,uint256 nonce,bytes data,uint256 validUntilTime)";
+
+const FORWARDER_DOMAIN_NAME: &str = "MinimalForwarder";
+const FORWARDER_DOMAIN_VERSION: &str = "0.0.1";
+
+sol! {
+    #[sol(rpc)]
+    interface IForwarder {
+        function execute(ForwardRequest memory req, bytes32 domainSeparator, bytes32 requestTypeHash, bytes memory suffixData, bytes memory signature) external payable returns (bool success, bytes memory ret);
+        function getNonce(address from) external view returns (uint256);
+    }
+
+    struct ForwardRequest {
+        address from;
+        address to;
+        uint256 value;
+        uint256 gas;
+        uint256 nonce;
+        bytes data;
+        uint256 validUntilTime;
+    }
+}
+
+/// Reads `from`'s current nonce on `forwarder`, to populate the next
+/// `ForwardRequest` without colliding with other pending relayed calls.
+pub async fn get_forwarder_nonce(
+    forwarder: Address,
+    from: Address,
+    config: &Config,
+) -> Result<U256, Box<dyn Error>> {
+    let contract = IForwarder::new(forwarder, &config.provider);
+    let nonce = contract.getNonce(from).call().await?._0;
+
+    Ok(nonce)
+}
+
+/// `keccak256` of the full EIP-712 type string: [`FORWARD_REQUEST_TYPE`]
+/// concatenated with the forwarder's custom suffix (e.g. a trailing
+/// `MetaTransaction(...)` field block registered on that forwarder). Pass an
+/// empty suffix for a forwarder that accepts the bare `ForwardRequest` type.
+pub fn request_type_hash(suffix: &str) -> FixedBytes<32> {
+    keccak256(format!("{FORWARD_REQUEST_TYPE}{suffix}"))
+}
+
+/// The EIP-712 domain separator for `forwarder` on the configured chain.
+pub fn domain_separator(forwarder: Address, chain_id: u64) -> FixedBytes<32> {
+    let domain = eip712_domain! {
+        name: FORWARDER_DOMAIN_NAME.to_string(),
+        version: FORWARDER_DOMAIN_VERSION.to_string(),
+        chain_id: chain_id,
+        verifying_contract: forwarder,
+    };
+
+    domain.separator()
+}
+
+/// Computes the EIP-712 signing hash for `req`, per the GSN pattern of
+/// appending the forwarder's `suffix_data` onto the encoded struct fields
+/// before they are hashed and folded into the domain-separated digest.
+pub fn forward_request_hash(
+    req: &ForwardRequest,
+    domain_separator: FixedBytes<32>,
+    request_type_hash: FixedBytes<32>,
+    suffix_data: &Bytes,
+) -> FixedBytes<32> {
+    let encoded_fields = (
+        request_type_hash,
+        req.from,
+        req.to,
+        req.value,
+        req.gas,
+        req.nonce,
+        keccak256(&req.data),
+        req.validUntilTime,
+    )
+        .abi_encode();
+
+    let mut struct_hash_input = encoded_fields;
+    struct_hash_input.extend_from_slice(suffix_data);
+    let struct_hash = keccak256(struct_hash_input);
+
+    let mut digest_input = Vec::with_capacity(66);
+    digest_input.extend_from_slice(&[0x19, 0x01]);
+    digest_input.extend_from_slice(domain_separator.as_slice());
+    digest_input.extend_from_slice(struct_hash.as_slice());
+
+    keccak256(digest_input)
+}
+
+/// Submits a meta-transaction: `req` was signed off-chain by its `from`
+/// account (see [`forward_request_hash`]), and this call lets a relayer
+/// broadcast it on-chain through `forwarder`'s `execute`, paying the gas
+/// itself. `suffix` must be the same EIP-712 type suffix (e.g. `""` for the
+/// bare `ForwardRequest` type) that was used to compute the signed hash, or
+/// the on-chain `requestTypeHash` won't match the signature and `execute`
+/// will revert.
+///
+/// `MinimalForwarder.execute` famously does *not* revert when the relayed
+/// call itself fails — it reports that via the `success` return value while
+/// the forwarder transaction still mines with status 1. So the call is
+/// simulated first to catch that case up front, before it's ever broadcast.
+pub async fn execute_forwarded(
+    forwarder: Address,
+    req: ForwardRequest,
+    suffix: &str,
+    suffix_data: Bytes,
+    signature: Signature,
+    config: &Config,
+) -> Result<AnyTransactionReceipt, Box<dyn Error>> {
+    let domain_separator = domain_separator(forwarder, config.chain_id);
+    let type_hash = request_type_hash(suffix);
+
+    let contract = IForwarder::new(forwarder, &config.provider);
+    let mut call = contract.execute(
+        req,
+        domain_separator,
+        type_hash,
+        suffix_data,
+        Bytes::from(signature.as_bytes().to_vec()),
+    );
+
+    if let Some(gas_oracle) = &config.gas_oracle {
+        let fees = gas_oracle.estimate_fees(&config.provider).await?;
+        call = call
+            .max_fee_per_gas(fees.max_fee_per_gas)
+            .max_priority_fee_per_gas(fees.max_priority_fee_per_gas);
+    }
+    if let Some(nonce_manager) = &config.nonce_manager {
+        call = call.nonce(nonce_manager.next());
+    }
+
+    let simulated = call.call().await?;
+    if !simulated.success {
+        return Err(format!(
+            "forwarded call reverted: forwarder returned success=false, ret={:?}",
+            simulated.ret
+        )
+        .into());
+    }
+
+    let receipt = call.send().await?.get_receipt().await?;
+
+    Ok(receipt)
+}
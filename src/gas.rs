@@ -0,0 +1,123 @@
+#![allow(missing_docs)]
+
+use crate::config::ProviderType;
+use alloy::{
+    eips::BlockNumberOrTag,
+    primitives::Address,
+    providers::Provider,
+};
+use async_trait::async_trait;
+use std::{
+    error::Error,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// An EIP-1559 fee estimate, in wei.
+#[derive(Debug, Clone, Copy)]
+pub struct FeeEstimate {
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+}
+
+/// Supplies the `maxFeePerGas`/`maxPriorityFeePerGas` a transaction should
+/// use. `execute_forwarded` checks `Config::gas_oracle` for one of these
+/// before submitting, rather than always deferring to `alloy`'s built-in
+/// `GasFiller`.
+#[async_trait]
+pub trait GasOracle: Send + Sync {
+    async fn estimate_fees(&self, provider: &ProviderType) -> Result<FeeEstimate, Box<dyn Error>>;
+}
+
+/// Derives fees from the node's own `eth_feeHistory`, using a configurable
+/// percentile of recent priority fees (the same approach `alloy`'s built-in
+/// `GasFiller` takes, but with the percentile left up to the caller).
+pub struct FeeHistoryGasOracle {
+    pub priority_fee_percentile: f64,
+}
+
+impl Default for FeeHistoryGasOracle {
+    fn default() -> Self {
+        FeeHistoryGasOracle {
+            priority_fee_percentile: 50.0,
+        }
+    }
+}
+
+#[async_trait]
+impl GasOracle for FeeHistoryGasOracle {
+    async fn estimate_fees(&self, provider: &ProviderType) -> Result<FeeEstimate, Box<dyn Error>> {
+        let fee_history = provider
+            .get_fee_history(10, BlockNumberOrTag::Latest, &[self.priority_fee_percentile])
+            .await?;
+
+        let base_fee = fee_history
+            .latest_block_base_fee()
+            .ok_or("missing base fee in fee history")?;
+
+        let priority_fees: Vec<u128> = fee_history
+            .reward
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|rewards| rewards.first().copied())
+            .collect();
+        let max_priority_fee_per_gas = if priority_fees.is_empty() {
+            0
+        } else {
+            priority_fees.iter().sum::<u128>() / priority_fees.len() as u128
+        };
+
+        Ok(FeeEstimate {
+            max_fee_per_gas: base_fee + max_priority_fee_per_gas,
+            max_priority_fee_per_gas,
+        })
+    }
+}
+
+/// Derives fees from an external HTTP price feed returning
+/// `{ "maxFeePerGas": ..., "maxPriorityFeePerGas": ... }` JSON, for callers
+/// who'd rather trust a gas station API than the node's own fee history.
+pub struct HttpGasOracle {
+    pub feed_url: String,
+}
+
+#[derive(serde::Deserialize)]
+struct FeedResponse {
+    #[serde(rename = "maxFeePerGas")]
+    max_fee_per_gas: u128,
+    #[serde(rename = "maxPriorityFeePerGas")]
+    max_priority_fee_per_gas: u128,
+}
+
+#[async_trait]
+impl GasOracle for HttpGasOracle {
+    async fn estimate_fees(&self, _provider: &ProviderType) -> Result<FeeEstimate, Box<dyn Error>> {
+        let feed: FeedResponse = reqwest::get(&self.feed_url).await?.json().await?;
+
+        Ok(FeeEstimate {
+            max_fee_per_gas: feed.max_fee_per_gas,
+            max_priority_fee_per_gas: feed.max_priority_fee_per_gas,
+        })
+    }
+}
+
+/// Caches an account's nonce locally and increments it in-process, so many
+/// transactions can be dispatched back-to-back without waiting for each one
+/// to land before reading the next nonce from the node.
+pub struct NonceManager {
+    next_nonce: AtomicU64,
+}
+
+impl NonceManager {
+    pub async fn new(address: Address, provider: &ProviderType) -> Result<Self, Box<dyn Error>> {
+        let nonce = provider.get_transaction_count(address).await?;
+
+        Ok(NonceManager {
+            next_nonce: AtomicU64::new(nonce),
+        })
+    }
+
+    /// Returns the next nonce to use and locally increments the counter.
+    pub fn next(&self) -> u64 {
+        self.next_nonce.fetch_add(1, Ordering::SeqCst)
+    }
+}
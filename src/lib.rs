@@ -1,6 +1,8 @@
 pub mod config;
 pub mod contracts;
+pub mod gas;
 pub mod monitor;
+pub mod primitives;
 pub mod signature;
 
 use config::Config;
@@ -0,0 +1,132 @@
+#![allow(missing_docs)]
+
+use crate::{config::Config, primitives::hash::eip191_hash};
+use alloy::primitives::{keccak256, Bytes};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+/// Flashbots' public relay; point at a different private relay by swapping
+/// this constant out if users ever need to configure it.
+const FLASHBOTS_RELAY_URL: &str = "https://relay.flashbots.net";
+
+#[derive(Serialize)]
+struct BundleParams {
+    txs: Vec<String>,
+    #[serde(rename = "blockNumber")]
+    block_number: String,
+    #[serde(rename = "minTimestamp", skip_serializing_if = "Option::is_none")]
+    min_timestamp: Option<u64>,
+    #[serde(rename = "maxTimestamp", skip_serializing_if = "Option::is_none")]
+    max_timestamp: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct JsonRpcRequest<T> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'static str,
+    params: [T; 1],
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+pub struct SimulatedTx {
+    pub hash: String,
+    #[serde(rename = "gasUsed")]
+    pub gas_used: u64,
+    pub revert: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct SimulationResult {
+    pub results: Vec<SimulatedTx>,
+}
+
+/// Submits an ordered bundle of already-signed raw transactions to the
+/// Flashbots relay via `eth_sendBundle`, landing in `target_block` privately
+/// instead of the public mempool.
+pub async fn send_bundle(
+    txs: Vec<Bytes>,
+    target_block: u64,
+    config: &Config,
+) -> Result<serde_json::Value, Box<dyn Error>> {
+    let params = BundleParams {
+        txs: txs.iter().map(|tx| tx.to_string()).collect(),
+        block_number: format!("0x{target_block:x}"),
+        min_timestamp: None,
+        max_timestamp: None,
+    };
+
+    let response: JsonRpcResponse<serde_json::Value> =
+        post_signed(FLASHBOTS_RELAY_URL, "eth_sendBundle", params, config).await?;
+
+    response
+        .result
+        .ok_or_else(|| format!("eth_sendBundle failed: {:?}", response.error).into())
+}
+
+/// Simulates a bundle against the pending block via `eth_callBundle`,
+/// reporting per-transaction gas usage and revert status without landing it.
+pub async fn simulate_bundle(
+    txs: Vec<Bytes>,
+    target_block: u64,
+    config: &Config,
+) -> Result<SimulationResult, Box<dyn Error>> {
+    let params = BundleParams {
+        txs: txs.iter().map(|tx| tx.to_string()).collect(),
+        block_number: format!("0x{target_block:x}"),
+        min_timestamp: None,
+        max_timestamp: None,
+    };
+
+    let response: JsonRpcResponse<SimulationResult> =
+        post_signed(FLASHBOTS_RELAY_URL, "eth_callBundle", params, config).await?;
+
+    response
+        .result
+        .ok_or_else(|| format!("eth_callBundle failed: {:?}", response.error).into())
+}
+
+/// Posts a Flashbots-style JSON-RPC request, signing the body with the
+/// `X-Flashbots-Signature` header the relay requires: `address:` followed by
+/// an EIP-191 personal-sign over the keccak256 of the JSON payload.
+async fn post_signed<T: Serialize, R: for<'de> Deserialize<'de>>(
+    url: &str,
+    method: &'static str,
+    params: T,
+    config: &Config,
+) -> Result<JsonRpcResponse<R>, Box<dyn Error>> {
+    let body = serde_json::to_vec(&JsonRpcRequest {
+        jsonrpc: "2.0",
+        id: 1,
+        method,
+        params: [params],
+    })?;
+
+    let body_digest = keccak256(&body);
+    let hash = eip191_hash(Bytes::from(body_digest.to_vec()));
+    let signature = config.signer.sign_hash(hash).await?;
+    let header_value = format!(
+        "{}:0x{}",
+        config.signer.address(),
+        alloy::hex::encode(signature.as_bytes())
+    );
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("X-Flashbots-Signature", header_value)
+        .body(body)
+        .send()
+        .await?
+        .json::<JsonRpcResponse<R>>()
+        .await?;
+
+    Ok(response)
+}
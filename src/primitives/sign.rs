@@ -1,25 +1,28 @@
 #![allow(missing_docs)]
 
 use crate::config::Config;
-use alloy::{
-    signers::{local::PrivateKeySigner, Signer},
-    sol,
-    sol_types::{eip712_domain, SolStruct},
-};
-use alloy_primitives::{keccak256, Address, Signature, U256};
+use alloy::{sol, sol_types::eip712_domain};
+use alloy_dyn_abi::TypedData;
+use alloy_primitives::{keccak256, Address, FixedBytes, Signature, U256};
 use serde::Serialize;
-use std::{error::Error, str::FromStr};
+use std::error::Error;
 
 pub async fn sign_message(content: String, config: Config) -> Result<Signature, Box<dyn Error>> {
-    let private_key = config.private_key;
+    config.signer.sign_message(content.as_bytes()).await
+}
 
-    let signer = PrivateKeySigner::from_str(&private_key).unwrap();
-    let signer = signer.with_chain_id(Some(config.chain_id));
+/// Signs an arbitrary EIP-712 payload given as the standard
+/// `{ "types", "primaryType", "domain", "message" }` JSON object, so callers
+/// don't need a bespoke Rust struct per domain (Seaport orders, DAO votes, etc).
+pub async fn sign_typed_data(json: &str, config: Config) -> Result<Signature, Box<dyn Error>> {
+    let typed_data: TypedData = serde_json::from_str(json)?;
+    let hash = typed_data.eip712_signing_hash()?;
 
-    let message = content.as_bytes();
-    let signature = signer.sign_message(message).await?;
+    sign_hash(hash, config).await
+}
 
-    Ok(signature)
+async fn sign_hash(hash: FixedBytes<32>, config: Config) -> Result<Signature, Box<dyn Error>> {
+    config.signer.sign_hash(hash).await
 }
 
 sol! {
@@ -46,9 +49,6 @@ pub async fn sign_eip712_message(
     permit_deadline: U256,
     config: Config,
 ) -> Result<Signature, Box<dyn Error>> {
-    let private_key = config.private_key;
-    let signer = PrivateKeySigner::from_str(&private_key).unwrap();
-
     let domain = eip712_domain! {
         name: domain_name.to_string(),
         version: domain_version.to_string(),
@@ -65,8 +65,8 @@ pub async fn sign_eip712_message(
         deadline: permit_deadline,
     };
 
-    let hash = permit.eip712_signing_hash(&domain);
-    let signature = signer.sign_hash(&hash).await?;
+    let typed_data = TypedData::from_struct(&permit, Some(domain));
+    let json = serde_json::to_string(&typed_data)?;
 
-    Ok(signature)
+    sign_typed_data(&json, config).await
 }
@@ -0,0 +1,76 @@
+#![allow(missing_docs)]
+
+use crate::config::Config;
+use alloy::{
+    primitives::{address, Address, Bytes, FixedBytes},
+    providers::Provider,
+    sol,
+};
+use std::error::Error;
+
+/// `bytes4(keccak256("isValidSignature(bytes32,bytes)"))`, returned by a
+/// compliant EIP-1271 contract wallet when the signature is valid.
+const EIP1271_MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+/// Trailing 32-byte marker appended to an ERC-6492 signature so that a
+/// verifier can tell a counterfactual-wallet signature apart from a plain one.
+const ERC6492_MAGIC_SUFFIX: [u8; 32] = [
+    0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92,
+    0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92,
+];
+
+/// Canonical `ValidateSigOffchain` helper used to verify ERC-6492 signatures
+/// against counterfactual (not-yet-deployed) wallets in a single `eth_call`.
+const UNIVERSAL_SIG_VALIDATOR: Address = address!("164af34fAF9879394370C7f09064127C043A35E9");
+
+sol! {
+    #[sol(rpc)]
+    interface IERC1271 {
+        function isValidSignature(bytes32 hash, bytes memory signature) external view returns (bytes4 magicValue);
+    }
+
+    #[sol(rpc)]
+    interface IUniversalSigValidator {
+        function isValidSig(address signer, bytes32 hash, bytes memory signature) external returns (bool);
+    }
+}
+
+/// Verifies a signature against `signer`, transparently handling plain EOA
+/// signatures (ecrecover), deployed EIP-1271 contract wallets, and
+/// undeployed ERC-6492 counterfactual wallets.
+pub async fn verify_signature(
+    signer: Address,
+    hash: FixedBytes<32>,
+    sig: Bytes,
+    config: &Config,
+) -> Result<bool, Box<dyn Error>> {
+    if is_erc6492(&sig) {
+        // Pass the still-wrapped signature through: the validator needs the
+        // `factory`/`factoryCalldata` prefix itself to run the counterfactual
+        // wallet's deploy step before it can perform the 1271 check.
+        let validator = IUniversalSigValidator::new(UNIVERSAL_SIG_VALIDATOR, &config.provider);
+        let is_valid = validator.isValidSig(signer, hash, sig).call().await?._0;
+
+        return Ok(is_valid);
+    }
+
+    let code = config.provider.get_code_at(signer).await?;
+    if code.is_empty() {
+        let recovered = sig.as_ref();
+        let signature = alloy::primitives::Signature::from_raw(recovered)?;
+        let recovered_address = signature.recover_address_from_prehash(&hash)?;
+        return Ok(recovered_address == signer);
+    }
+
+    let wallet = IERC1271::new(signer, &config.provider);
+    let magic_value = wallet.isValidSignature(hash, sig).call().await?.magicValue;
+
+    Ok(magic_value.0 == EIP1271_MAGIC_VALUE)
+}
+
+/// Whether `sig` carries the trailing ERC-6492 magic suffix, marking it as a
+/// wrapped `(address factory, bytes factoryCalldata, bytes innerSignature)`
+/// signature for a counterfactual (not-yet-deployed) wallet.
+fn is_erc6492(sig: &Bytes) -> bool {
+    sig.len() >= 32 && sig[sig.len() - 32..] == ERC6492_MAGIC_SUFFIX
+}
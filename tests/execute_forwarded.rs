@@ -0,0 +1,40 @@
+use alloy_primitives::{address, Bytes, U256};
+use coordinate::config;
+use coordinate::contracts::{self, ForwardRequest};
+use dotenv::dotenv;
+use std::env;
+
+#[tokio::test]
+async fn test_execute_forwarded() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv().ok();
+    let etherscan_key = env::var("ETHERSCAN_KEY")?;
+    let rpc_url = env::var("RPC_URL")?;
+    let private_key = env::var("PRIVATE_KEY")?;
+    let config = config::Config::init(private_key, rpc_url, etherscan_key).await?;
+
+    let forwarder = address!("E041608922d06a4F26C0d4c27d8bCD01daf1f792");
+    let nonce = contracts::get_forwarder_nonce(forwarder, config.caller, &config).await?;
+
+    let req = ForwardRequest {
+        from: config.caller,
+        to: address!("B4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc"),
+        value: U256::ZERO,
+        gas: U256::from(100_000),
+        nonce,
+        data: Bytes::default(),
+        validUntilTime: U256::from(9_999_999_999u64),
+    };
+
+    let domain_separator = contracts::domain_separator(forwarder, config.chain_id);
+    let type_hash = contracts::request_type_hash("");
+    let suffix_data = Bytes::default();
+    let hash = contracts::forward_request_hash(&req, domain_separator, type_hash, &suffix_data);
+    let signature = config.signer.sign_hash(hash).await?;
+
+    let receipt =
+        contracts::execute_forwarded(forwarder, req, "", suffix_data, signature, &config).await?;
+
+    println!("{:?}", receipt.transaction_hash);
+
+    Ok(())
+}
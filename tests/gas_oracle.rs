@@ -0,0 +1,24 @@
+use coordinate::config;
+use coordinate::gas::{FeeHistoryGasOracle, GasOracle, NonceManager};
+use dotenv::dotenv;
+use std::env;
+
+#[tokio::test]
+async fn test_gas_oracle_and_nonce_manager() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv().ok();
+    let etherscan_key = env::var("ETHERSCAN_KEY")?;
+    let rpc_url = env::var("RPC_URL")?;
+    let private_key = env::var("PRIVATE_KEY")?;
+    let config = config::Config::init(private_key, rpc_url, etherscan_key).await?;
+
+    let oracle = FeeHistoryGasOracle::default();
+    let fees = oracle.estimate_fees(&config.provider).await?;
+    assert!(fees.max_fee_per_gas >= fees.max_priority_fee_per_gas);
+
+    let nonce_manager = NonceManager::new(config.caller, &config.provider).await?;
+    let first = nonce_manager.next();
+    let second = nonce_manager.next();
+    assert_eq!(second, first + 1);
+
+    Ok(())
+}
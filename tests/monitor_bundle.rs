@@ -0,0 +1,24 @@
+use alloy::providers::Provider;
+use alloy_primitives::Bytes;
+use coordinate::config;
+use coordinate::monitor;
+use dotenv::dotenv;
+use std::env;
+
+#[tokio::test]
+async fn test_simulate_bundle() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv().ok();
+    let etherscan_key = env::var("ETHERSCAN_KEY")?;
+    let rpc_url = env::var("RPC_URL")?;
+    let private_key = env::var("PRIVATE_KEY")?;
+    let config = config::Config::init(private_key, rpc_url, etherscan_key).await?;
+
+    let target_block = config.provider.get_block_number().await? + 1;
+    let txs = vec![Bytes::from_static(b"\x02")];
+
+    let result = monitor::simulate_bundle(txs, target_block, &config).await?;
+
+    println!("{} tx(s) simulated", result.results.len());
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use coordinate::config;
+use coordinate::primitives;
+use dotenv::dotenv;
+use std::env;
+
+#[tokio::test]
+async fn test_sign_typed_data() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv().ok();
+    let etherscan_key = env::var("ETHERSCAN_KEY")?;
+    let rpc_url = env::var("RPC_URL")?;
+    let private_key = env::var("PRIVATE_KEY")?;
+    let config = config::Config::init(private_key, rpc_url, etherscan_key).await?;
+
+    let json = r#"{
+        "types": {
+            "EIP712Domain": [
+                {"name": "name", "type": "string"},
+                {"name": "version", "type": "string"},
+                {"name": "chainId", "type": "uint256"},
+                {"name": "verifyingContract", "type": "address"}
+            ],
+            "Mail": [
+                {"name": "from", "type": "address"},
+                {"name": "to", "type": "address"},
+                {"name": "contents", "type": "string"}
+            ]
+        },
+        "primaryType": "Mail",
+        "domain": {
+            "name": "Ether Mail",
+            "version": "1",
+            "chainId": 1,
+            "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+        },
+        "message": {
+            "from": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826",
+            "to": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB",
+            "contents": "Hello, Bob!"
+        }
+    }"#;
+
+    let signature = primitives::sign::sign_typed_data(json, config).await?;
+
+    println!("{:?}", signature);
+
+    Ok(())
+}
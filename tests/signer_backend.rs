@@ -0,0 +1,18 @@
+use coordinate::config::LocalSigner;
+use coordinate::config::SignerBackend;
+use dotenv::dotenv;
+use std::env;
+
+#[tokio::test]
+async fn test_local_signer() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv().ok();
+    let private_key = env::var("PRIVATE_KEY")?;
+
+    let signer = LocalSigner::new(&private_key, 1)?;
+    let signature = signer.sign_message(b"hello").await?;
+    let recovered = signature.recover_address_from_msg(b"hello")?;
+
+    assert_eq!(recovered, signer.address());
+
+    Ok(())
+}
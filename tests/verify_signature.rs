@@ -0,0 +1,25 @@
+use alloy_primitives::{Bytes, FixedBytes};
+use coordinate::config;
+use coordinate::signature;
+use dotenv::dotenv;
+use std::env;
+
+#[tokio::test]
+async fn test_verify_signature() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv().ok();
+    let etherscan_key = env::var("ETHERSCAN_KEY")?;
+    let rpc_url = env::var("RPC_URL")?;
+    let private_key = env::var("PRIVATE_KEY")?;
+    let config = config::Config::init(private_key, rpc_url, etherscan_key).await?;
+
+    let hash = FixedBytes::<32>::ZERO;
+    let signer_address = config.signer.address();
+    let raw_signature = config.signer.sign_hash(hash).await?;
+    let sig = Bytes::from(raw_signature.as_bytes().to_vec());
+
+    let is_valid = signature::verify_signature(signer_address, hash, sig, &config).await?;
+
+    assert!(is_valid);
+
+    Ok(())
+}